@@ -1,8 +1,20 @@
+use std::sync::Mutex;
+use std::time::Duration;
+
+use reqwest::Client as AsyncHttpClient;
 use reqwest::StatusCode;
 use reqwest::blocking::Client as BlockingClient;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+/// Default base URI of the Rikkicom `Call2FA` API.
+const DEFAULT_BASE_URI: &str = "https://api-call2fa.rikkicom.io";
+/// Default API version.
+const DEFAULT_VERSION: &str = "v1";
+/// Default base delay between retries when retries are enabled.
+const DEFAULT_RETRY_BASE_DELAY: Duration = Duration::from_millis(200);
+
 /// Custom Error type for the client, covering various failure scenarios.
 #[derive(Error, Debug)]
 pub enum ClientError {
@@ -22,12 +34,20 @@ pub enum ClientError {
     EmptyId,
     #[error("HTTP request failed: {0}")]
     RequestFailed(#[from] reqwest::Error),
-    #[error("API returned an unexpected status code: {0}")]
-    UnexpectedStatusCode(StatusCode),
+    #[error("API returned an unexpected status code {status}: {body}")]
+    UnexpectedStatusCode { status: StatusCode, body: String },
     #[error("Failed to deserialize JSON response: {0}")]
     DeserializationFailed(String),
     #[error("JWT not found in authentication response")]
     JwtNotFound,
+    #[error("API error ({status}): {code} - {message}")]
+    Api {
+        status: StatusCode,
+        code: String,
+        message: String,
+    },
+    #[error("Resource not found: {0}")]
+    NotFound(String),
 }
 
 /// Represents the client for the Rikkicom `Call2FA` API.
@@ -37,7 +57,12 @@ pub struct Client {
     http_client: BlockingClient,
     base_uri: String,
     version: String,
-    jwt: String,
+    jwt: Mutex<String>,
+    login: String,
+    password: String,
+    auto_reauth: bool,
+    max_retries: u32,
+    retry_base_delay: Duration,
 }
 
 // Structs for API request bodies
@@ -72,8 +97,87 @@ struct AuthResponse {
     jwt: String,
 }
 
+// Struct for the API's error response body, e.g. `{"code": "...", "message": "..."}`
+#[derive(Deserialize)]
+struct ApiError {
+    code: String,
+    message: String,
+}
+
+/// The `call_id` returned after successfully placing a call.
+#[derive(Debug, Deserialize)]
+pub struct CallResponse {
+    pub call_id: String,
+}
+
+/// Information about a previously placed call, as returned by [`Client::info`]
+/// and [`AsyncClient::info`].
+#[derive(Debug, Deserialize)]
+pub struct CallInfo {
+    pub call_id: String,
+    pub status: String,
+    pub phone_number: Option<String>,
+    pub created_at: Option<String>,
+}
+
+/// Builds a `ClientError` from a non-2xx response body. A `404` is surfaced as
+/// [`ClientError::NotFound`] so callers can branch on it directly; otherwise the body
+/// is parsed as an [`ApiError`] when possible, falling back to
+/// [`ClientError::UnexpectedStatusCode`] (with the raw body attached) when it isn't JSON.
+fn api_error_from_body(status: StatusCode, body: String) -> ClientError {
+    if status == StatusCode::NOT_FOUND {
+        return ClientError::NotFound(body);
+    }
+
+    match serde_json::from_str::<ApiError>(&body) {
+        Ok(ApiError { code, message }) => ClientError::Api {
+            status,
+            code,
+            message,
+        },
+        Err(_) => ClientError::UnexpectedStatusCode { status, body },
+    }
+}
+
+/// Whether a response status is worth retrying: a `5xx` or a `429 Too Many Requests`.
+fn is_retryable_status(status: StatusCode) -> bool {
+    status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS
+}
+
+/// Whether a transport-level error is worth retrying: a connection error or a timeout.
+fn is_retryable_error(error: &reqwest::Error) -> bool {
+    error.is_connect() || error.is_timeout()
+}
+
+/// Computes the exponential backoff delay for a given retry attempt
+/// (`base * 2^attempt`), plus a small random jitter to avoid thundering herds.
+fn backoff_delay(base: Duration, attempt: u32) -> Duration {
+    let exponential = base.saturating_mul(1u32 << attempt.min(16));
+    exponential.saturating_add(jitter())
+}
+
+/// A small pseudo-random delay (0-49ms) derived from the current time, used to
+/// spread out retries that would otherwise fire in lockstep.
+fn jitter() -> Duration {
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |d| d.subsec_nanos());
+    Duration::from_millis(u64::from(nanos) % 50)
+}
+
+/// Parses a `Retry-After` header value expressed as a number of seconds.
+fn retry_after_delay(headers: &reqwest::header::HeaderMap) -> Option<Duration> {
+    headers
+        .get(reqwest::header::RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<u64>().ok())
+        .map(Duration::from_secs)
+}
+
 impl Client {
-    /// Creates a new client instance and authenticates with the API.
+    /// Creates a new client instance and authenticates with the API, using the
+    /// default transport settings. Use [`ClientBuilder`] to customize the base URI,
+    /// timeout, proxy, or headers.
     ///
     /// # Arguments
     ///
@@ -88,25 +192,89 @@ impl Client {
     ///
     /// Will return `Err` if parameters are empty
     pub fn new(login: &str, password: &str) -> Result<Self, ClientError> {
-        if login.is_empty() {
-            return Err(ClientError::EmptyLogin);
-        }
-        if password.is_empty() {
-            return Err(ClientError::EmptyPassword);
+        ClientBuilder::new(login, password).build()
+    }
+
+    /// Returns a clone of the currently held JWT.
+    fn current_jwt(&self) -> String {
+        self.jwt.lock().expect("jwt mutex poisoned").clone()
+    }
+
+    /// Replaces the currently held JWT, e.g. after a successful re-authentication.
+    fn set_jwt(&self, jwt: String) {
+        *self.jwt.lock().expect("jwt mutex poisoned") = jwt;
+    }
+
+    /// Re-authenticates against the API and stores the freshly issued JWT.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if authentication fails.
+    pub fn refresh_token(&self) -> Result<(), ClientError> {
+        let jwt = Self::receive_jwt(
+            &self.http_client,
+            &self.base_uri,
+            &self.version,
+            &self.login,
+            &self.password,
+        )?;
+        self.set_jwt(jwt);
+        Ok(())
+    }
+
+    /// Enables or disables transparent re-authentication when a request comes back
+    /// `401 Unauthorized` because the JWT has expired. Enabled by default.
+    pub fn set_auto_reauth(&mut self, enabled: bool) {
+        self.auto_reauth = enabled;
+    }
+
+    /// Sends a request built by `build`, transparently re-authenticating and retrying
+    /// once if the API responds `401 Unauthorized` and auto re-auth is enabled.
+    fn send_authenticated(
+        &self,
+        build: impl Fn(&str) -> reqwest::blocking::RequestBuilder,
+    ) -> Result<reqwest::blocking::Response, ClientError> {
+        let response = build(&self.current_jwt()).send()?;
+
+        if response.status() == StatusCode::UNAUTHORIZED && self.auto_reauth {
+            self.refresh_token()?;
+            return Ok(build(&self.current_jwt()).send()?);
         }
 
-        let http_client = BlockingClient::new();
-        let base_uri = "https://api-call2fa.rikkicom.io".to_string();
-        let version = "v1".to_string();
+        Ok(response)
+    }
 
-        let jwt = Self::receive_jwt(&http_client, &base_uri, &version, login, password)?;
+    /// Sends a request built by `build`, retrying on connection errors, timeouts, and
+    /// `5xx`/`429` responses with exponential backoff, honoring a `Retry-After` header
+    /// on `429`. Never retries 4xx responses (other than 429). Disabled by default;
+    /// enable via [`ClientBuilder::max_retries`].
+    fn send_with_retries(
+        &self,
+        build: impl Fn(&str) -> reqwest::blocking::RequestBuilder,
+    ) -> Result<reqwest::blocking::Response, ClientError> {
+        let mut attempt = 0;
 
-        Ok(Self {
-            http_client,
-            base_uri,
-            version,
-            jwt,
-        })
+        loop {
+            let result = self.send_authenticated(&build);
+
+            let delay = match &result {
+                Ok(response) if is_retryable_status(response.status()) => {
+                    Some(retry_after_delay(response.headers()))
+                }
+                Err(ClientError::RequestFailed(error)) if is_retryable_error(error) => Some(None),
+                _ => None,
+            };
+
+            let Some(retry_after) = delay else {
+                return result;
+            };
+            if attempt >= self.max_retries {
+                return result;
+            }
+
+            std::thread::sleep(retry_after.unwrap_or_else(|| backoff_delay(self.retry_base_delay, attempt)));
+            attempt += 1;
+        }
     }
 
     /// Fetches the JSON Web Token from the API.
@@ -125,14 +293,15 @@ impl Client {
         let auth_data = AuthRequest { login, password };
 
         let response = http_client.post(&uri).json(&auth_data).send()?;
+        let status = response.status();
 
-        if response.status() == StatusCode::OK {
+        if status == StatusCode::OK {
             let auth_response: AuthResponse = response
                 .json()
                 .map_err(|e| ClientError::DeserializationFailed(e.to_string()))?;
             Ok(auth_response.jwt)
         } else {
-            Err(ClientError::UnexpectedStatusCode(response.status()))
+            Err(api_error_from_body(status, response.text()?))
         }
     }
 
@@ -145,7 +314,7 @@ impl Client {
         &self,
         phone_number: &str,
         callback_url: &str,
-    ) -> Result<serde_json::Value, ClientError> {
+    ) -> Result<CallResponse, ClientError> {
         if phone_number.is_empty() {
             return Err(ClientError::EmptyPhoneNumber);
         }
@@ -156,17 +325,14 @@ impl Client {
             callback_url,
         };
 
-        let response = self
-            .http_client
-            .post(&uri)
-            .bearer_auth(&self.jwt)
-            .json(&call_data)
-            .send()?;
+        let response =
+            self.send_with_retries(|jwt| self.http_client.post(&uri).bearer_auth(jwt).json(&call_data))?;
 
         if response.status() == StatusCode::CREATED {
             Ok(response.json()?)
         } else {
-            Err(ClientError::UnexpectedStatusCode(response.status()))
+            let status = response.status();
+            Err(api_error_from_body(status, response.text()?))
         }
     }
 
@@ -180,7 +346,7 @@ impl Client {
         phone_number: &str,
         pool_id: &str,
         use_six_digits: bool,
-    ) -> Result<serde_json::Value, ClientError> {
+    ) -> Result<CallResponse, ClientError> {
         if phone_number.is_empty() {
             return Err(ClientError::EmptyPhoneNumber);
         }
@@ -196,17 +362,14 @@ impl Client {
         let uri = self.make_full_uri(&method);
         let call_data = PoolCallRequest { phone_number };
 
-        let response = self
-            .http_client
-            .post(&uri)
-            .bearer_auth(&self.jwt)
-            .json(&call_data)
-            .send()?;
+        let response =
+            self.send_with_retries(|jwt| self.http_client.post(&uri).bearer_auth(jwt).json(&call_data))?;
 
         if response.status() == StatusCode::CREATED {
             Ok(response.json()?)
         } else {
-            Err(ClientError::UnexpectedStatusCode(response.status()))
+            let status = response.status();
+            Err(api_error_from_body(status, response.text()?))
         }
     }
 
@@ -220,7 +383,7 @@ impl Client {
         phone_number: &str,
         code: &str,
         lang: &str,
-    ) -> Result<serde_json::Value, ClientError> {
+    ) -> Result<CallResponse, ClientError> {
         if phone_number.is_empty() {
             return Err(ClientError::EmptyPhoneNumber);
         }
@@ -238,17 +401,14 @@ impl Client {
             lang,
         };
 
-        let response = self
-            .http_client
-            .post(&uri)
-            .bearer_auth(&self.jwt)
-            .json(&call_data)
-            .send()?;
+        let response =
+            self.send_with_retries(|jwt| self.http_client.post(&uri).bearer_auth(jwt).json(&call_data))?;
 
         if response.status() == StatusCode::CREATED {
             Ok(response.json()?)
         } else {
-            Err(ClientError::UnexpectedStatusCode(response.status()))
+            let status = response.status();
+            Err(api_error_from_body(status, response.text()?))
         }
     }
 
@@ -256,19 +416,480 @@ impl Client {
     /// # Errors
     ///
     /// Will return `Err` if `id` is empty or status code is not 200.
-    pub fn info(&self, id: &str) -> Result<serde_json::Value, ClientError> {
+    pub fn info(&self, id: &str) -> Result<CallInfo, ClientError> {
         if id.is_empty() {
             return Err(ClientError::EmptyId);
         }
 
         let uri = self.make_full_uri(&format!("call/{id}"));
 
-        let response = self.http_client.get(&uri).bearer_auth(&self.jwt).send()?;
+        let response = self.send_with_retries(|jwt| self.http_client.get(&uri).bearer_auth(jwt))?;
 
         if response.status() == StatusCode::OK {
             Ok(response.json()?)
         } else {
-            Err(ClientError::UnexpectedStatusCode(response.status()))
+            let status = response.status();
+            Err(api_error_from_body(status, response.text()?))
+        }
+    }
+
+    /// Creates a full URI to the specified API method.
+    fn make_full_uri(&self, method: &str) -> String {
+        format!("{}/{}/{}/", self.base_uri, self.version, method)
+    }
+
+    /// Returns the current API version.
+    #[must_use]
+    pub fn version(&self) -> &str {
+        &self.version
+    }
+
+    /// Sets a different API version.
+    pub fn set_version(&mut self, version: String) {
+        self.version = version;
+    }
+}
+
+/// Builder for [`Client`], letting callers configure the base URI, API version,
+/// timeout, proxy, and default headers of the underlying transport before
+/// authenticating. `Client::new` is a thin wrapper over this builder with today's
+/// defaults.
+pub struct ClientBuilder {
+    login: String,
+    password: String,
+    base_uri: String,
+    version: String,
+    timeout: Option<Duration>,
+    proxy: Option<String>,
+    user_agent: Option<String>,
+    default_headers: HeaderMap,
+    danger_accept_invalid_certs: bool,
+    max_retries: u32,
+    retry_base_delay: Duration,
+}
+
+impl ClientBuilder {
+    /// Creates a new builder with the default base URI and API version.
+    #[must_use]
+    pub fn new(login: &str, password: &str) -> Self {
+        Self {
+            login: login.to_string(),
+            password: password.to_string(),
+            base_uri: DEFAULT_BASE_URI.to_string(),
+            version: DEFAULT_VERSION.to_string(),
+            timeout: None,
+            proxy: None,
+            user_agent: None,
+            default_headers: HeaderMap::new(),
+            danger_accept_invalid_certs: false,
+            max_retries: 0,
+            retry_base_delay: DEFAULT_RETRY_BASE_DELAY,
+        }
+    }
+
+    /// Sets the maximum number of retries for requests that fail with a connection
+    /// error, a timeout, or a `5xx`/`429` response. Disabled (`0`) by default.
+    #[must_use]
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Sets the base delay for the exponential backoff between retries
+    /// (`retry_base_delay * 2^attempt`, plus jitter).
+    #[must_use]
+    pub fn retry_base_delay(mut self, retry_base_delay: Duration) -> Self {
+        self.retry_base_delay = retry_base_delay;
+        self
+    }
+
+    /// Sets the API base URI, e.g. to point at a staging or mock endpoint for tests.
+    #[must_use]
+    pub fn base_uri(mut self, base_uri: impl Into<String>) -> Self {
+        self.base_uri = base_uri.into();
+        self
+    }
+
+    /// Sets the API version.
+    #[must_use]
+    pub fn version(mut self, version: impl Into<String>) -> Self {
+        self.version = version.into();
+        self
+    }
+
+    /// Sets the request timeout for the underlying HTTP client.
+    #[must_use]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Routes requests through the given proxy, e.g. `"http://proxy.local:8080"`.
+    #[must_use]
+    pub fn proxy(mut self, proxy_uri: impl Into<String>) -> Self {
+        self.proxy = Some(proxy_uri.into());
+        self
+    }
+
+    /// Sets a custom `User-Agent` header.
+    #[must_use]
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Adds a default header sent with every request. Invalid header names/values
+    /// are silently ignored.
+    #[must_use]
+    pub fn default_header(mut self, name: &str, value: &str) -> Self {
+        if let (Ok(name), Ok(value)) = (
+            HeaderName::from_bytes(name.as_bytes()),
+            HeaderValue::from_str(value),
+        ) {
+            self.default_headers.insert(name, value);
+        }
+        self
+    }
+
+    /// Disables TLS certificate validation. Only useful against self-hosted test
+    /// gateways; never enable this against the production API.
+    #[must_use]
+    pub fn danger_accept_invalid_certs(mut self, accept: bool) -> Self {
+        self.danger_accept_invalid_certs = accept;
+        self
+    }
+
+    /// Builds the underlying `reqwest` client and authenticates with the API.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if parameters are empty, the transport configuration
+    /// (e.g. the proxy URI) is invalid, or authentication fails.
+    pub fn build(self) -> Result<Client, ClientError> {
+        if self.login.is_empty() {
+            return Err(ClientError::EmptyLogin);
+        }
+        if self.password.is_empty() {
+            return Err(ClientError::EmptyPassword);
+        }
+
+        let mut http_client_builder = BlockingClient::builder()
+            .default_headers(self.default_headers)
+            .danger_accept_invalid_certs(self.danger_accept_invalid_certs);
+
+        if let Some(timeout) = self.timeout {
+            http_client_builder = http_client_builder.timeout(timeout);
+        }
+        if let Some(user_agent) = &self.user_agent {
+            http_client_builder = http_client_builder.user_agent(user_agent);
+        }
+        if let Some(proxy_uri) = &self.proxy {
+            http_client_builder = http_client_builder.proxy(reqwest::Proxy::all(proxy_uri)?);
+        }
+
+        let http_client = http_client_builder.build()?;
+        let jwt = Client::receive_jwt(
+            &http_client,
+            &self.base_uri,
+            &self.version,
+            &self.login,
+            &self.password,
+        )?;
+
+        Ok(Client {
+            http_client,
+            base_uri: self.base_uri,
+            version: self.version,
+            jwt: Mutex::new(jwt),
+            login: self.login,
+            password: self.password,
+            auto_reauth: true,
+            max_retries: self.max_retries,
+            retry_base_delay: self.retry_base_delay,
+        })
+    }
+}
+
+/// Async counterpart of [`Client`], built on [`reqwest::Client`] for use from
+/// inside an existing async runtime (e.g. tokio/axum) instead of blocking a thread.
+#[derive(Debug)]
+pub struct AsyncClient {
+    #[allow(clippy::struct_field_names)]
+    http_client: AsyncHttpClient,
+    base_uri: String,
+    version: String,
+    jwt: Mutex<String>,
+    login: String,
+    password: String,
+    auto_reauth: bool,
+    max_retries: u32,
+    retry_base_delay: Duration,
+}
+
+impl AsyncClient {
+    /// Creates a new async client instance and authenticates with the API, using
+    /// the default transport settings. Use [`AsyncClientBuilder`] to customize the
+    /// base URI, timeout, proxy, or headers.
+    ///
+    /// # Arguments
+    ///
+    /// * `login` - The customer's API login.
+    /// * `password` - The customer's API password.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the new `AsyncClient` instance or a `ClientError`.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if parameters are empty
+    pub async fn new(login: &str, password: &str) -> Result<Self, ClientError> {
+        AsyncClientBuilder::new(login, password).build().await
+    }
+
+    /// Returns a clone of the currently held JWT.
+    fn current_jwt(&self) -> String {
+        self.jwt.lock().expect("jwt mutex poisoned").clone()
+    }
+
+    /// Replaces the currently held JWT, e.g. after a successful re-authentication.
+    fn set_jwt(&self, jwt: String) {
+        *self.jwt.lock().expect("jwt mutex poisoned") = jwt;
+    }
+
+    /// Re-authenticates against the API and stores the freshly issued JWT.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if authentication fails.
+    pub async fn refresh_token(&self) -> Result<(), ClientError> {
+        let jwt = Self::receive_jwt(
+            &self.http_client,
+            &self.base_uri,
+            &self.version,
+            &self.login,
+            &self.password,
+        )
+        .await?;
+        self.set_jwt(jwt);
+        Ok(())
+    }
+
+    /// Enables or disables transparent re-authentication when a request comes back
+    /// `401 Unauthorized` because the JWT has expired. Enabled by default.
+    pub fn set_auto_reauth(&mut self, enabled: bool) {
+        self.auto_reauth = enabled;
+    }
+
+    /// Sends a request built by `build`, transparently re-authenticating and retrying
+    /// once if the API responds `401 Unauthorized` and auto re-auth is enabled.
+    async fn send_authenticated(
+        &self,
+        build: impl Fn(&str) -> reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response, ClientError> {
+        let response = build(&self.current_jwt()).send().await?;
+
+        if response.status() == StatusCode::UNAUTHORIZED && self.auto_reauth {
+            self.refresh_token().await?;
+            return Ok(build(&self.current_jwt()).send().await?);
+        }
+
+        Ok(response)
+    }
+
+    /// Sends a request built by `build`, retrying on connection errors, timeouts, and
+    /// `5xx`/`429` responses with exponential backoff, honoring a `Retry-After` header
+    /// on `429`. Never retries 4xx responses (other than 429). Disabled by default;
+    /// enable via [`AsyncClientBuilder::max_retries`].
+    async fn send_with_retries(
+        &self,
+        build: impl Fn(&str) -> reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response, ClientError> {
+        let mut attempt = 0;
+
+        loop {
+            let result = self.send_authenticated(&build).await;
+
+            let delay = match &result {
+                Ok(response) if is_retryable_status(response.status()) => {
+                    Some(retry_after_delay(response.headers()))
+                }
+                Err(ClientError::RequestFailed(error)) if is_retryable_error(error) => Some(None),
+                _ => None,
+            };
+
+            let Some(retry_after) = delay else {
+                return result;
+            };
+            if attempt >= self.max_retries {
+                return result;
+            }
+
+            tokio::time::sleep(retry_after.unwrap_or_else(|| backoff_delay(self.retry_base_delay, attempt)))
+                .await;
+            attempt += 1;
+        }
+    }
+
+    /// Fetches the JSON Web Token from the API.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if status code is not 200.
+    async fn receive_jwt(
+        http_client: &AsyncHttpClient,
+        base_uri: &str,
+        version: &str,
+        login: &str,
+        password: &str,
+    ) -> Result<String, ClientError> {
+        let uri = format!("{base_uri}/{version}/auth/");
+        let auth_data = AuthRequest { login, password };
+
+        let response = http_client.post(&uri).json(&auth_data).send().await?;
+        let status = response.status();
+
+        if status == StatusCode::OK {
+            let auth_response: AuthResponse = response
+                .json()
+                .await
+                .map_err(|e| ClientError::DeserializationFailed(e.to_string()))?;
+            Ok(auth_response.jwt)
+        } else {
+            Err(api_error_from_body(status, response.text().await?))
+        }
+    }
+
+    /// Initiates a new call.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if status code is not 201.
+    pub async fn call(
+        &self,
+        phone_number: &str,
+        callback_url: &str,
+    ) -> Result<CallResponse, ClientError> {
+        if phone_number.is_empty() {
+            return Err(ClientError::EmptyPhoneNumber);
+        }
+
+        let uri = self.make_full_uri("call");
+        let call_data = CallRequest {
+            phone_number,
+            callback_url,
+        };
+
+        let response = self
+            .send_with_retries(|jwt| self.http_client.post(&uri).bearer_auth(jwt).json(&call_data))
+            .await?;
+
+        if response.status() == StatusCode::CREATED {
+            Ok(response.json().await?)
+        } else {
+            let status = response.status();
+            Err(api_error_from_body(status, response.text().await?))
+        }
+    }
+
+    /// Initiates a new call via the last digits mode.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if parameters are empty or status code is not 201.
+    pub async fn call_via_last_digits(
+        &self,
+        phone_number: &str,
+        pool_id: &str,
+        use_six_digits: bool,
+    ) -> Result<CallResponse, ClientError> {
+        if phone_number.is_empty() {
+            return Err(ClientError::EmptyPhoneNumber);
+        }
+        if pool_id.is_empty() {
+            return Err(ClientError::EmptyPoolId);
+        }
+
+        let method = if use_six_digits {
+            format!("pool/{pool_id}/call/six-digits")
+        } else {
+            format!("pool/{pool_id}/call")
+        };
+        let uri = self.make_full_uri(&method);
+        let call_data = PoolCallRequest { phone_number };
+
+        let response = self
+            .send_with_retries(|jwt| self.http_client.post(&uri).bearer_auth(jwt).json(&call_data))
+            .await?;
+
+        if response.status() == StatusCode::CREATED {
+            Ok(response.json().await?)
+        } else {
+            let status = response.status();
+            Err(api_error_from_body(status, response.text().await?))
+        }
+    }
+
+    /// Initiates a new call with a verification code.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if parameters are empty or status code is not 201.
+    pub async fn call_with_code(
+        &self,
+        phone_number: &str,
+        code: &str,
+        lang: &str,
+    ) -> Result<CallResponse, ClientError> {
+        if phone_number.is_empty() {
+            return Err(ClientError::EmptyPhoneNumber);
+        }
+        if code.is_empty() {
+            return Err(ClientError::EmptyCode);
+        }
+        if lang.is_empty() {
+            return Err(ClientError::EmptyLang);
+        }
+
+        let uri = self.make_full_uri("code/call");
+        let call_data = CallWithCodeRequest {
+            phone_number,
+            code,
+            lang,
+        };
+
+        let response = self
+            .send_with_retries(|jwt| self.http_client.post(&uri).bearer_auth(jwt).json(&call_data))
+            .await?;
+
+        if response.status() == StatusCode::CREATED {
+            Ok(response.json().await?)
+        } else {
+            let status = response.status();
+            Err(api_error_from_body(status, response.text().await?))
+        }
+    }
+
+    /// Gets information about a call by its identifier.
+    /// # Errors
+    ///
+    /// Will return `Err` if `id` is empty or status code is not 200.
+    pub async fn info(&self, id: &str) -> Result<CallInfo, ClientError> {
+        if id.is_empty() {
+            return Err(ClientError::EmptyId);
+        }
+
+        let uri = self.make_full_uri(&format!("call/{id}"));
+
+        let response = self
+            .send_with_retries(|jwt| self.http_client.get(&uri).bearer_auth(jwt))
+            .await?;
+
+        if response.status() == StatusCode::OK {
+            Ok(response.json().await?)
+        } else {
+            let status = response.status();
+            Err(api_error_from_body(status, response.text().await?))
         }
     }
 
@@ -289,6 +910,167 @@ impl Client {
     }
 }
 
+/// Builder for [`AsyncClient`], letting callers configure the base URI, API version,
+/// timeout, proxy, and default headers of the underlying transport before
+/// authenticating. `AsyncClient::new` is a thin wrapper over this builder with
+/// today's defaults.
+pub struct AsyncClientBuilder {
+    login: String,
+    password: String,
+    base_uri: String,
+    version: String,
+    timeout: Option<Duration>,
+    proxy: Option<String>,
+    user_agent: Option<String>,
+    default_headers: HeaderMap,
+    danger_accept_invalid_certs: bool,
+    max_retries: u32,
+    retry_base_delay: Duration,
+}
+
+impl AsyncClientBuilder {
+    /// Creates a new builder with the default base URI and API version.
+    #[must_use]
+    pub fn new(login: &str, password: &str) -> Self {
+        Self {
+            login: login.to_string(),
+            password: password.to_string(),
+            base_uri: DEFAULT_BASE_URI.to_string(),
+            version: DEFAULT_VERSION.to_string(),
+            timeout: None,
+            proxy: None,
+            user_agent: None,
+            default_headers: HeaderMap::new(),
+            danger_accept_invalid_certs: false,
+            max_retries: 0,
+            retry_base_delay: DEFAULT_RETRY_BASE_DELAY,
+        }
+    }
+
+    /// Sets the maximum number of retries for requests that fail with a connection
+    /// error, a timeout, or a `5xx`/`429` response. Disabled (`0`) by default.
+    #[must_use]
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Sets the base delay for the exponential backoff between retries
+    /// (`retry_base_delay * 2^attempt`, plus jitter).
+    #[must_use]
+    pub fn retry_base_delay(mut self, retry_base_delay: Duration) -> Self {
+        self.retry_base_delay = retry_base_delay;
+        self
+    }
+
+    /// Sets the API base URI, e.g. to point at a staging or mock endpoint for tests.
+    #[must_use]
+    pub fn base_uri(mut self, base_uri: impl Into<String>) -> Self {
+        self.base_uri = base_uri.into();
+        self
+    }
+
+    /// Sets the API version.
+    #[must_use]
+    pub fn version(mut self, version: impl Into<String>) -> Self {
+        self.version = version.into();
+        self
+    }
+
+    /// Sets the request timeout for the underlying HTTP client.
+    #[must_use]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Routes requests through the given proxy, e.g. `"http://proxy.local:8080"`.
+    #[must_use]
+    pub fn proxy(mut self, proxy_uri: impl Into<String>) -> Self {
+        self.proxy = Some(proxy_uri.into());
+        self
+    }
+
+    /// Sets a custom `User-Agent` header.
+    #[must_use]
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Adds a default header sent with every request. Invalid header names/values
+    /// are silently ignored.
+    #[must_use]
+    pub fn default_header(mut self, name: &str, value: &str) -> Self {
+        if let (Ok(name), Ok(value)) = (
+            HeaderName::from_bytes(name.as_bytes()),
+            HeaderValue::from_str(value),
+        ) {
+            self.default_headers.insert(name, value);
+        }
+        self
+    }
+
+    /// Disables TLS certificate validation. Only useful against self-hosted test
+    /// gateways; never enable this against the production API.
+    #[must_use]
+    pub fn danger_accept_invalid_certs(mut self, accept: bool) -> Self {
+        self.danger_accept_invalid_certs = accept;
+        self
+    }
+
+    /// Builds the underlying `reqwest` client and authenticates with the API.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if parameters are empty, the transport configuration
+    /// (e.g. the proxy URI) is invalid, or authentication fails.
+    pub async fn build(self) -> Result<AsyncClient, ClientError> {
+        if self.login.is_empty() {
+            return Err(ClientError::EmptyLogin);
+        }
+        if self.password.is_empty() {
+            return Err(ClientError::EmptyPassword);
+        }
+
+        let mut http_client_builder = AsyncHttpClient::builder()
+            .default_headers(self.default_headers)
+            .danger_accept_invalid_certs(self.danger_accept_invalid_certs);
+
+        if let Some(timeout) = self.timeout {
+            http_client_builder = http_client_builder.timeout(timeout);
+        }
+        if let Some(user_agent) = &self.user_agent {
+            http_client_builder = http_client_builder.user_agent(user_agent);
+        }
+        if let Some(proxy_uri) = &self.proxy {
+            http_client_builder = http_client_builder.proxy(reqwest::Proxy::all(proxy_uri)?);
+        }
+
+        let http_client = http_client_builder.build()?;
+        let jwt = AsyncClient::receive_jwt(
+            &http_client,
+            &self.base_uri,
+            &self.version,
+            &self.login,
+            &self.password,
+        )
+        .await?;
+
+        Ok(AsyncClient {
+            http_client,
+            base_uri: self.base_uri,
+            version: self.version,
+            jwt: Mutex::new(jwt),
+            login: self.login,
+            password: self.password,
+            auto_reauth: true,
+            max_retries: self.max_retries,
+            retry_base_delay: self.retry_base_delay,
+        })
+    }
+}
+
 /// This function contains the core logic and can return a Result.
 fn run(login: &str, password: &str, call_to: &str, callback_url: &str) -> Result<(), ClientError> {
     // Create the Call2FA client. The `?` operator will propagate any error from `Client::new`.
@@ -297,18 +1079,7 @@ fn run(login: &str, password: &str, call_to: &str, callback_url: &str) -> Result
 
     // Make a call. The `?` operator will propagate any error from `client.call`.
     let response = client.call(call_to, callback_url)?;
-    println!("Call initiated successfully.");
-
-    // Print the successful response. `serde_json::to_string_pretty` is used for nice formatting.
-    match serde_json::to_string_pretty(&response) {
-        Ok(json_string) => println!("Response:\n{json_string}"),
-        Err(_) => println!("Could not format response JSON. Raw: {response:?}"),
-    }
-
-    // Result looks like the following:
-    // {
-    //   "call_id": "95831458"
-    // }
+    println!("Call initiated successfully. call_id: {}", response.call_id);
 
     Ok(())
 }